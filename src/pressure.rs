@@ -1,48 +1,295 @@
 use std::cmp::min;
-use std::error::Error;
+use std::fmt;
 
 use log::{debug, error /* info, warn */};
 
-use rppal::i2c::I2c;
+use embedded_hal::i2c::I2c;
 
 // Pressure sensor I2C address
-const ADDR_PRESSURE_SENSOR: u16 = 0x4D;
+const ADDR_PRESSURE_SENSOR: u8 = 0x4D;
 
-pub struct Pressure {
-    i2c: rppal::i2c::I2c,
-    baseline: i32,
+// Plausible output window for the sensor's ~12-bit count, after the
+// read_io() baseline offset has been applied. A probe reading outside this
+// window means no device (or the wrong device) is on the bus.
+const PLAUSIBLE_OUTPUT_MIN: i32 = -2048;
+const PLAUSIBLE_OUTPUT_MAX: i32 = 2047;
+
+/// Errors returned while initializing or communicating with the sensor.
+#[derive(Debug)]
+pub enum PressureError<E> {
+    /// The underlying I2C bus returned an error.
+    I2c(E),
+    /// The probe read performed during `init`/`new` fell outside the
+    /// sensor's plausible output window, so no device (or the wrong
+    /// device) appears to be on the bus.
+    DeviceNotDetected,
 }
 
-impl Pressure {
-    pub fn init() -> Result<Pressure, Box<dyn Error>> {
-        debug!("I2C: Configuring bus ...");
+impl<E: fmt::Debug> fmt::Display for PressureError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PressureError::I2c(e) => write!(f, "I2C error: {:?}", e),
+            PressureError::DeviceNotDetected => write!(f, "pressure sensor not detected"),
+        }
+    }
+}
 
-        let maybe_i2c = I2c::new();
+impl<E: fmt::Debug> std::error::Error for PressureError<E> {}
 
-        let mut i2c = match maybe_i2c {
-            Ok(i2c) => i2c,
-            Err(e) => {
-                error!("Failed to initialize I2C.  Check raspi-config.");
-                return Err(Box::new(e));
-            }
-        };
+impl<E> From<E> for PressureError<E> {
+    fn from(e: E) -> Self {
+        PressureError::I2c(e)
+    }
+}
 
-        debug!(
-            "I2C: Created on bus {} at {} Hz",
-            i2c.bus(),
-            i2c.clock_speed()?
+/// Where the sensor's usable output-count band sits within its full output
+/// range, per the manufacturer's transfer-function convention (as used by
+/// e.g. the Honeywell mprls0025pa family).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferFunction {
+    /// 10%-90% of the full output-count range.
+    A,
+    /// 2.5%-22.5% of the full output-count range.
+    B,
+}
+
+impl TransferFunction {
+    /// Usable band, in tenths of a percent of the full output-count range.
+    fn band_permille(self) -> (i64, i64) {
+        match self {
+            TransferFunction::A => (100, 900),
+            TransferFunction::B => (25, 225),
+        }
+    }
+}
+
+/// Oversampling ratio: the number of consecutive raw reads averaged into a
+/// single sample, borrowed from the hp203b altimeter driver's approach to
+/// de-jittering noisy readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Osr {
+    X1,
+    X2,
+    X4,
+    X8,
+    X16,
+    X32,
+    X64,
+    X128,
+}
+
+impl Osr {
+    fn samples(self) -> u32 {
+        match self {
+            Osr::X1 => 1,
+            Osr::X2 => 2,
+            Osr::X4 => 4,
+            Osr::X8 => 8,
+            Osr::X16 => 16,
+            Osr::X32 => 32,
+            Osr::X64 => 64,
+            Osr::X128 => 128,
+        }
+    }
+}
+
+/// Calibration for converting raw sensor counts into physical pressure
+/// units (e.g. pascal).
+#[derive(Debug, Clone, Copy)]
+pub struct PressureConfig {
+    /// Raw count corresponding to `p_min`.
+    out_min: i64,
+    /// Raw count corresponding to `p_max`.
+    out_max: i64,
+    /// Physical pressure at `out_min`.
+    p_min: i64,
+    /// Physical pressure at `out_max`.
+    p_max: i64,
+    /// Number of raw samples averaged per reading.
+    osr: Osr,
+    /// IIR low-pass coefficient applied after oversampling, in (0, 1]. A
+    /// value of 1.0 disables filtering.
+    filter_alpha: f32,
+    /// Divisor mapping delta pressure counts to 0-127 on the blow (positive)
+    /// side.
+    blow_scale: i32,
+    /// Divisor mapping delta pressure counts to 0-127 on the draw (negative)
+    /// side.
+    draw_scale: i32,
+    /// Counts of dead-zone around zero before the blow side starts
+    /// responding.
+    blow_dead_zone: i32,
+    /// Counts of dead-zone around zero before the draw side starts
+    /// responding.
+    draw_dead_zone: i32,
+    /// Counts of dead-band around zero within which the signal is treated
+    /// as "at rest" for baseline drift tracking.
+    drift_dead_band: i32,
+    /// Consecutive at-rest samples required before nudging the baseline.
+    drift_dwell_count: u32,
+    /// Leaky-integrator shift applied to the baseline nudge:
+    /// `baseline += (sample - baseline) >> drift_k`, where `sample` is the
+    /// oversampled, filtered reading.
+    drift_k: u32,
+}
+
+impl PressureConfig {
+    /// Derive `out_min`/`out_max` from a transfer function and the sensor's
+    /// full output-count range, and pair them with the rated pressure span
+    /// `p_min`..`p_max`.
+    ///
+    /// `full_scale_counts` is the sensor's raw output span (e.g. 4096 for a
+    /// 12-bit sensor) *before* `read_io`'s mid-scale offset. Since every raw
+    /// reading fed into `apply_transfer_function` has already had `read_io`'s
+    /// fixed offset of 2048 subtracted, `out_min`/`out_max` are shifted by
+    /// the same offset here so they stay in that frame of reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `full_scale_counts` is not positive, or if it's too small
+    /// for the transfer function's band to produce distinct `out_min`/
+    /// `out_max` counts, since `apply_transfer_function` divides by their
+    /// difference.
+    pub fn new(
+        transfer_function: TransferFunction,
+        full_scale_counts: i64,
+        p_min: i64,
+        p_max: i64,
+    ) -> PressureConfig {
+        assert!(full_scale_counts > 0, "full_scale_counts must be positive");
+        let (lo, hi) = transfer_function.band_permille();
+        let offset = 2048;
+        let out_min = full_scale_counts * lo / 1000 - offset;
+        let out_max = full_scale_counts * hi / 1000 - offset;
+        assert!(
+            out_max != out_min,
+            "full_scale_counts too small for the selected transfer function band"
         );
+        PressureConfig {
+            out_min,
+            out_max,
+            p_min,
+            p_max,
+            osr: Osr::X4,
+            filter_alpha: 0.2,
+            blow_scale: 6,
+            draw_scale: 6,
+            blow_dead_zone: 0,
+            draw_dead_zone: 0,
+            drift_dead_band: 10,
+            drift_dwell_count: 50,
+            drift_k: 6,
+        }
+    }
 
-        // Set the I2C slave address to the device we're communicating with.
-        i2c.set_slave_address(ADDR_PRESSURE_SENSOR)?;
+    /// Override the oversampling ratio.
+    pub fn with_osr(mut self, osr: Osr) -> Self {
+        self.osr = osr;
+        self
+    }
 
-        debug!("I2C: slave address set to {}", ADDR_PRESSURE_SENSOR);
+    /// Override the IIR low-pass filter coefficient. Must be in (0, 1].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `filter_alpha` is not in (0, 1].
+    pub fn with_filter_alpha(mut self, filter_alpha: f32) -> Self {
+        assert!(
+            filter_alpha > 0.0 && filter_alpha <= 1.0,
+            "filter_alpha must be in (0, 1]"
+        );
+        self.filter_alpha = filter_alpha;
+        self
+    }
+
+    /// Override the blow-side and draw-side scaling divisors used by
+    /// `read_bipolar`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either divisor is not positive: a zero divisor panics on
+    /// the first `read`/`read_bipolar` call, and a negative divisor would
+    /// flip the sign of the mapped delta and wrap into a bogus value on the
+    /// cast to `u8`.
+    pub fn with_scales(mut self, blow_scale: i32, draw_scale: i32) -> Self {
+        assert!(blow_scale > 0, "blow_scale must be positive");
+        assert!(draw_scale > 0, "draw_scale must be positive");
+        self.blow_scale = blow_scale;
+        self.draw_scale = draw_scale;
+        self
+    }
 
+    /// Override the blow-side and draw-side dead-zones (in raw counts)
+    /// used by `read_bipolar`.
+    pub fn with_dead_zones(mut self, blow_dead_zone: i32, draw_dead_zone: i32) -> Self {
+        self.blow_dead_zone = blow_dead_zone;
+        self.draw_dead_zone = draw_dead_zone;
+        self
+    }
+
+    /// Override continuous baseline drift compensation: `dead_band` is how
+    /// close to zero a sample must be to count as "at rest", `dwell_count`
+    /// is how many consecutive at-rest samples are required before nudging
+    /// the baseline, and `k` sets the leaky-integrator's correction rate
+    /// (higher `k` nudges more slowly).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than 32, since `update_drift` shifts an
+    /// `i32` right by `k`.
+    pub fn with_drift_compensation(mut self, dead_band: i32, dwell_count: u32, k: u32) -> Self {
+        assert!(k < 32, "drift k must be less than 32");
+        self.drift_dead_band = dead_band;
+        self.drift_dwell_count = dwell_count;
+        self.drift_k = k;
+        self
+    }
+}
+
+impl Default for PressureConfig {
+    /// Transfer function "A" over the sensor's 12-bit output range, with a
+    /// placeholder pressure span of 0..100000 pascal until the caller
+    /// supplies a real calibration.
+    fn default() -> Self {
+        PressureConfig::new(TransferFunction::A, 4096, 0, 100_000)
+    }
+}
+
+pub struct Pressure<I> {
+    i2c: I,
+    baseline: i32,
+    config: PressureConfig,
+    /// Last output of the IIR low-pass filter; `None` until the first sample.
+    filtered: Option<f32>,
+    /// Consecutive at-rest samples seen so far, for drift compensation.
+    drift_dwell: u32,
+}
+
+impl<I> Pressure<I>
+where
+    I: I2c,
+{
+    /// Wrap an already-configured I2C bus and capture a baseline reading.
+    ///
+    /// The bus need not be addressed beforehand; the sensor's 7-bit address
+    /// is applied on every transaction.
+    pub fn new(mut i2c: I, config: PressureConfig) -> Result<Pressure<I>, PressureError<I::Error>> {
         let baseline = Pressure::read_io(&mut i2c)?;
 
+        if !(PLAUSIBLE_OUTPUT_MIN..=PLAUSIBLE_OUTPUT_MAX).contains(&baseline) {
+            error!(
+                "I2C: probe read {} outside plausible output window {}..={}",
+                baseline, PLAUSIBLE_OUTPUT_MIN, PLAUSIBLE_OUTPUT_MAX
+            );
+            return Err(PressureError::DeviceNotDetected);
+        }
+
         let sensor = Pressure {
-            i2c: i2c,
-            baseline: baseline,
+            i2c,
+            baseline,
+            config,
+            filtered: None,
+            drift_dwell: 0,
         };
 
         debug!("I2C: baseline set to {}", sensor.baseline);
@@ -50,18 +297,111 @@ impl Pressure {
         Ok(sensor)
     }
 
-    pub fn read(&mut self) -> Result<i32, Box<dyn Error>> {
-        let pressure = Pressure::read_io(&mut self.i2c)?;
-        // Compress the the range returned by the sensor to 0-127 required
-        // for MIDI.  TODO:  Make this configurable
-        const PRESSURE_SCALING_FACTOR: i32 = 6;
-        Ok(min((pressure - self.baseline) / PRESSURE_SCALING_FACTOR, 127))
+    pub fn read(&mut self) -> Result<i32, I::Error> {
+        let pressure = self.sample()?;
+        // Compress the range returned by the sensor towards the 0-127
+        // required for MIDI. Only the top is clamped: draw (negative)
+        // pressure comes back as a negative value scaled by the blow-side
+        // divisor; see `read_bipolar` for separate 0-127 blow and draw values.
+        Ok(min(
+            (pressure - self.baseline) / self.config.blow_scale,
+            127,
+        ))
+    }
+
+    /// Read the current pressure and split it into independent blow
+    /// (positive) and draw (negative) 0-127 MIDI values, so the draw side
+    /// of the sensor's range can drive its own controller instead of sharing
+    /// `read`'s single signed value.
+    pub fn read_bipolar(&mut self) -> Result<(u8, u8), I::Error> {
+        let delta = self.sample()? - self.baseline;
+
+        let blow = if delta > self.config.blow_dead_zone {
+            min(
+                (delta - self.config.blow_dead_zone) / self.config.blow_scale,
+                127,
+            )
+        } else {
+            0
+        };
+
+        let draw = if delta < -self.config.draw_dead_zone {
+            min(
+                (-delta - self.config.draw_dead_zone) / self.config.draw_scale,
+                127,
+            )
+        } else {
+            0
+        };
+
+        Ok((blow as u8, draw as u8))
+    }
+
+    /// Read the current pressure, calibrated into the physical units of the
+    /// configured `PressureConfig`.
+    pub fn read_pressure(&mut self) -> Result<i64, I::Error> {
+        let raw = self.sample()? as i64;
+        Ok(Self::apply_transfer_function(raw, &self.config))
+    }
+
+    /// Average `config.osr` consecutive raw reads, then run the result
+    /// through the IIR low-pass filter.
+    fn sample(&mut self) -> Result<i32, I::Error> {
+        let samples = self.config.osr.samples();
+        let mut sum: i64 = 0;
+        for _ in 0..samples {
+            sum += Pressure::read_io(&mut self.i2c)? as i64;
+        }
+        let averaged = (sum / samples as i64) as f32;
+
+        let alpha = self.config.filter_alpha;
+        let filtered = match self.filtered {
+            Some(previous) => previous + alpha * (averaged - previous),
+            None => averaged,
+        };
+        self.filtered = Some(filtered);
+
+        let sample = filtered.round() as i32;
+        self.update_drift(sample);
+
+        Ok(sample)
+    }
+
+    /// Track whether recent samples have sat within the drift dead-band
+    /// around the baseline, and nudge the baseline towards the current
+    /// reading via a leaky integrator once enough of them have.
+    fn update_drift(&mut self, sample: i32) {
+        if (sample - self.baseline).abs() <= self.config.drift_dead_band {
+            self.drift_dwell += 1;
+            if self.drift_dwell >= self.config.drift_dwell_count {
+                self.baseline += (sample - self.baseline) >> self.config.drift_k;
+                self.drift_dwell = 0;
+            }
+        } else {
+            self.drift_dwell = 0;
+        }
     }
 
-    fn read_io(i2c: &mut rppal::i2c::I2c) -> Result<i32, Box<dyn Error>> {
+    /// Force an immediate baseline reset from a fresh raw reading,
+    /// discarding any in-progress drift-compensation dwell count.
+    pub fn recalibrate(&mut self) -> Result<(), I::Error> {
+        self.baseline = Pressure::read_io(&mut self.i2c)?;
+        self.drift_dwell = 0;
+        Ok(())
+    }
+
+    /// Map a raw count to physical units via the configured two-point
+    /// calibration, rounding to the nearest unit rather than truncating.
+    fn apply_transfer_function(raw: i64, config: &PressureConfig) -> i64 {
+        let numerator = (raw - config.out_min) * (config.p_max - config.p_min);
+        let denominator = config.out_max - config.out_min;
+        round_div(numerator, denominator) + config.p_min
+    }
+
+    fn read_io(i2c: &mut I) -> Result<i32, I::Error> {
         let mut reg = [0u8; 2];
         let mut result;
-        i2c.read(&mut reg)?;
+        i2c.read(ADDR_PRESSURE_SENSOR, &mut reg)?;
         result = reg[0] as i32;
         result <<= 8;
         result |= reg[1] as i32;
@@ -70,22 +410,315 @@ impl Pressure {
     }
 }
 
+/// Integer division that rounds to the nearest value instead of truncating
+/// toward zero. `denominator` must be positive.
+fn round_div(numerator: i64, denominator: i64) -> i64 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if 2 * remainder >= denominator {
+        quotient + 1
+    } else if 2 * remainder <= -denominator {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+impl Pressure<rppal::i2c::I2c> {
+    /// Convenience constructor for the on-device Raspberry Pi bus.
+    pub fn init(
+        config: PressureConfig,
+    ) -> Result<Pressure<rppal::i2c::I2c>, PressureError<rppal::i2c::Error>> {
+        debug!("I2C: Configuring bus ...");
+
+        let i2c = rppal::i2c::I2c::new().map_err(|e| {
+            error!("Failed to initialize I2C.  Check raspi-config.");
+            PressureError::I2c(e)
+        })?;
+
+        debug!(
+            "I2C: Created on bus {} at {} Hz",
+            i2c.bus(),
+            i2c.clock_speed()?
+        );
+
+        Pressure::new(i2c, config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Import names from outer (for mod tests) scope.
     use super::*;
 
+    use std::error::Error;
     use std::thread;
     use std::time::Duration;
 
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    // Raw register bytes for a count of 2048 + delta, as returned by the sensor.
+    fn reg_bytes(delta: i32) -> Vec<u8> {
+        let raw = (2048 + delta) as u16;
+        vec![(raw >> 8) as u8, (raw & 0xff) as u8]
+    }
+
+    #[test]
+    fn new_captures_baseline() {
+        let expectations = [I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0))];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn new_rejects_implausible_probe_reading() {
+        // A floating/unresponsive bus typically reads back all-ones. Use a
+        // plain stub here rather than `I2cMock`, since construction fails
+        // after the single probe read and drops the bus, leaving no mock to
+        // call `done()` on.
+        struct FixedReadBus(Vec<u8>);
+
+        impl embedded_hal::i2c::ErrorType for FixedReadBus {
+            type Error = std::convert::Infallible;
+        }
+
+        impl embedded_hal::i2c::I2c for FixedReadBus {
+            fn transaction(
+                &mut self,
+                _address: u8,
+                operations: &mut [embedded_hal::i2c::Operation<'_>],
+            ) -> Result<(), Self::Error> {
+                for op in operations {
+                    if let embedded_hal::i2c::Operation::Read(buffer) = op {
+                        buffer.copy_from_slice(&self.0);
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let i2c = FixedReadBus(vec![0xff, 0xff]);
+        match Pressure::new(i2c, PressureConfig::default()) {
+            Err(PressureError::DeviceNotDetected) => {}
+            other => panic!("expected DeviceNotDetected, got {:?}", other.is_ok()),
+        }
+    }
+
+    #[test]
+    fn read_subtracts_baseline_and_scales() {
+        // Default Osr::X4 oversamples four identical reads per `read()` call.
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(60)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(60)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(60)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(60)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
+        let pressure = sensor.read().expect("read failed");
+        assert_eq!(pressure, 10);
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn read_bipolar_maps_blow_and_draw_independently() {
+        let config = PressureConfig::default()
+            .with_osr(Osr::X1)
+            .with_filter_alpha(1.0)
+            .with_scales(6, 12)
+            .with_dead_zones(5, 5);
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(65)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(-65)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(3)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, config).expect("Failed to initialize pressure sensor");
+
+        // Blow side: (65 - 5) / 6 = 10, draw side silent.
+        assert_eq!(sensor.read_bipolar().expect("read_bipolar failed"), (10, 0));
+        // Draw side: (65 - 5) / 12 = 5, blow side silent.
+        assert_eq!(sensor.read_bipolar().expect("read_bipolar failed"), (0, 5));
+        // Inside the dead-zone on both sides.
+        assert_eq!(sensor.read_bipolar().expect("read_bipolar failed"), (0, 0));
+        sensor.i2c.done();
+    }
+
+    #[test]
+    #[should_panic(expected = "blow_scale must be positive")]
+    fn with_scales_rejects_zero_blow_scale() {
+        PressureConfig::default().with_scales(0, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "draw_scale must be positive")]
+    fn with_scales_rejects_zero_draw_scale() {
+        PressureConfig::default().with_scales(6, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "blow_scale must be positive")]
+    fn with_scales_rejects_negative_blow_scale() {
+        PressureConfig::default().with_scales(-6, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "draw_scale must be positive")]
+    fn with_scales_rejects_negative_draw_scale() {
+        PressureConfig::default().with_scales(6, -6);
+    }
+
+    #[test]
+    #[should_panic(expected = "drift k must be less than 32")]
+    fn with_drift_compensation_rejects_oversized_k() {
+        PressureConfig::default().with_drift_compensation(10, 50, 32);
+    }
+
+    #[test]
+    fn baseline_drifts_after_dwell_threshold() {
+        let config = PressureConfig::default()
+            .with_osr(Osr::X1)
+            .with_filter_alpha(1.0)
+            .with_drift_compensation(5, 3, 2);
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(4)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(4)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(4)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, config).expect("Failed to initialize pressure sensor");
+        sensor.sample().expect("sample failed");
+        sensor.sample().expect("sample failed");
+        sensor.sample().expect("sample failed");
+        // After 3 consecutive at-rest samples, the baseline nudges towards
+        // 4 by (4 - 0) >> 2 = 1.
+        assert_eq!(sensor.baseline, 1);
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn recalibrate_resets_baseline_and_dwell() {
+        // Default Osr::X4: one at-rest sample (four reads) starts a dwell
+        // count before the recalibration read.
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(500)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
+        sensor.sample().expect("sample failed");
+        assert_eq!(sensor.drift_dwell, 1);
+        sensor.recalibrate().expect("recalibrate failed");
+        assert_eq!(sensor.baseline, 500);
+        assert_eq!(sensor.drift_dwell, 0);
+        sensor.i2c.done();
+    }
+
+    #[test]
+    #[should_panic(expected = "full_scale_counts must be positive")]
+    fn new_rejects_non_positive_full_scale_counts() {
+        PressureConfig::new(TransferFunction::A, 0, 0, 100_000);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "full_scale_counts too small for the selected transfer function band"
+    )]
+    fn new_rejects_full_scale_counts_too_small_for_band() {
+        PressureConfig::new(TransferFunction::A, 1, 0, 100_000);
+    }
+
+    #[test]
+    fn read_pressure_applies_calibration() {
+        // Transfer function A over a 4096-count range maps out_min/out_max
+        // onto 0..100000 pascal, in the raw frame of reference read_io
+        // already offsets by -2048 (i.e. out_min = 409-2048 = -1639).
+        // Disable oversampling/filtering so the calibration math is
+        // isolated.
+        let config = PressureConfig::new(TransferFunction::A, 4096, 0, 100_000)
+            .with_osr(Osr::X1)
+            .with_filter_alpha(1.0);
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(-1639)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, config).expect("Failed to initialize pressure sensor");
+        let pressure = sensor.read_pressure().expect("read_pressure failed");
+        assert_eq!(pressure, 0);
+        sensor.i2c.done();
+    }
+
+    #[test]
+    fn read_pressure_applies_calibration_at_top_of_range() {
+        // out_max = 3686-2048 = 1638, within read_io's reachable raw range
+        // (-2048..=2047). Before the offset fix this was unreachable
+        // (3686), so a reading at the sensor's natural center came out far
+        // below p_min instead of near the middle of the configured span.
+        let config = PressureConfig::new(TransferFunction::A, 4096, 0, 100_000)
+            .with_osr(Osr::X1)
+            .with_filter_alpha(1.0);
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(1638)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, config).expect("Failed to initialize pressure sensor");
+        let pressure = sensor.read_pressure().expect("read_pressure failed");
+        assert_eq!(pressure, 100_000);
+        sensor.i2c.done();
+    }
+
+    #[test]
+    #[should_panic(expected = "filter_alpha must be in (0, 1]")]
+    fn with_filter_alpha_rejects_zero() {
+        PressureConfig::default().with_filter_alpha(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "filter_alpha must be in (0, 1]")]
+    fn with_filter_alpha_rejects_above_one() {
+        PressureConfig::default().with_filter_alpha(1.1);
+    }
+
+    #[test]
+    fn sample_averages_oversampled_reads() {
+        let config = PressureConfig::default().with_osr(Osr::X4);
+        let expectations = [
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(0)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(20)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(40)),
+            I2cTransaction::read(ADDR_PRESSURE_SENSOR, reg_bytes(60)),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Pressure::new(i2c, config).expect("Failed to initialize pressure sensor");
+        let sample = sensor.sample().expect("sample failed");
+        assert_eq!(sample, 30);
+        sensor.i2c.done();
+    }
+
     #[test]
     fn init() {
-        let mut _sensor = Pressure::init().expect("Failed to initialize pressure sensor");
+        let mut _sensor = Pressure::init(PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
     }
 
     #[test]
     fn read() -> Result<(), Box<dyn Error>> {
-        let mut sensor = Pressure::init().expect("Failed to initialize pressure sensor");
+        let mut sensor = Pressure::init(PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
         let _pressure = sensor.read()?;
         Ok(())
     }
@@ -100,7 +733,8 @@ mod tests {
     #[ignore]
     fn pressure_step() -> Result<(), Box<dyn Error>> {
         println!("Blow and draw air from the mouthpiece...");
-        let mut sensor = Pressure::init().expect("Failed to initialize pressure sensor");
+        let mut sensor = Pressure::init(PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
         let mut pressure_positive_detected = false;
         let mut pressure_negative_detected = false;
         for _ in 0..100 {
@@ -138,7 +772,8 @@ mod tests {
     #[ignore]
     fn read_io() -> Result<(), Box<dyn Error>> {
         println!("Blow and draw on the mouthpiece...");
-        let mut sensor = Pressure::init().expect("Failed to initialize pressure sensor");
+        let mut sensor = Pressure::init(PressureConfig::default())
+            .expect("Failed to initialize pressure sensor");
         let mut max_val: i32 = 0;
         let mut min_val: i32 = i32::MAX;
         let mut pressure_range_detected = false;